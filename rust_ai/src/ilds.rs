@@ -6,6 +6,71 @@
 //! which can be useful for various applications like sampling, optimization,
 //! or numerical integration.
 
+/// Decomposes `n` into its digits in the given `base`, least-significant
+/// digit first — the same order the radix-reflection routines below consume
+/// and produce.
+///
+/// `base` must be at least 2, as with any positional number system.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::ilds::to_digits;
+/// assert_eq!(to_digits(13, 2), vec![1, 0, 1, 1]); // 13 = 0b1101
+/// ```
+pub fn to_digits(mut n: u32, base: u32) -> Vec<u32> {
+    let mut digits = Vec::new();
+    if n == 0 {
+        return digits;
+    }
+    while n != 0 {
+        digits.push(n % base);
+        n /= base;
+    }
+    digits
+}
+
+/// Reconstructs the integer whose least-significant-digit-first decomposition
+/// in `base` is `digits`. The inverse of [`to_digits`].
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::ilds::from_digits;
+/// assert_eq!(from_digits(&[1, 0, 1, 1], 2), 13);
+/// ```
+pub fn from_digits(digits: &[u32], base: u32) -> u32 {
+    digits.iter().rev().fold(0, |acc, &digit| acc * base + digit)
+}
+
+/// Computes the radical-inverse ("reflection") of `n` in `base`, scaled up
+/// by `scale` digits so the result is an integer rather than a fraction in
+/// `[0, 1)`. This is the primitive [`VdCorput::pop`] is built on; it is
+/// exposed directly so callers can build custom (e.g. scrambled) integer
+/// sequences without reimplementing the radix reflection.
+///
+/// `base` must be at least 2.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::ilds::reflect;
+/// assert_eq!(reflect(1, 2, 10), 512); // 0.5 * 2^10
+/// ```
+pub fn reflect(n: u32, base: u32, scale: u32) -> u32 {
+    let mut factor = base.pow(scale);
+    let mut k = n;
+    let mut vdc = 0;
+
+    while k != 0 {
+        factor /= base;
+        let remainder = k % base;
+        k /= base;
+        vdc += remainder * factor;
+    }
+    vdc
+}
+
 /// Integer Van der Corput sequence generator
 ///
 /// Generates integer values of the Van der Corput sequence with a specified scale.
@@ -20,10 +85,8 @@
 /// ```
 pub struct VdCorput {
     base: u32,
-    #[allow(dead_code)] // Used for documentation and API consistency
     scale: u32,
     count: u32,
-    factor: u32,
 }
 
 impl VdCorput {
@@ -34,34 +97,22 @@ impl VdCorput {
     /// * `base` - The base of the number system (defaults to 2 if not specified)
     /// * `scale` - The scale factor determining the number of digits that can be represented
     pub fn new(base: u32, scale: u32) -> Self {
-        let factor = base.pow(scale);
         Self {
             base,
             scale,
             count: 0,
-            factor,
         }
     }
-    
+
     /// Generates the next integer value in the sequence
     ///
     /// Increments the count and calculates the next integer value
     /// in the Van der Corput sequence.
     pub fn pop(&mut self) -> u32 {
         self.count += 1;
-        let mut k = self.count;
-        let mut vdc = 0;
-        let mut factor = self.factor;
-        
-        while k != 0 {
-            factor /= self.base;
-            let remainder = k % self.base;
-            k /= self.base;
-            vdc += remainder * factor;
-        }
-        vdc
+        reflect(self.count, self.base, self.scale)
     }
-    
+
     /// Resets the state of the sequence generator to a specific seed value
     ///
     /// # Arguments
@@ -70,6 +121,37 @@ impl VdCorput {
     pub fn reseed(&mut self, seed: u32) {
         self.count = seed;
     }
+
+    /// Returns the current count (seed) driving the sequence
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets the current count (seed) driving the sequence, equivalent to [`VdCorput::reseed`]
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count;
+    }
+
+    /// Returns the current base of the number system
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Reconfigures the base of the number system, taking effect on the next
+    /// `pop`. `base` must be at least 2.
+    pub fn set_base(&mut self, base: u32) {
+        self.base = base;
+    }
+
+    /// Returns the current scale (number of representable digits)
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Reconfigures the scale, taking effect on the next `pop`
+    pub fn set_scale(&mut self, scale: u32) {
+        self.scale = scale;
+    }
 }
 
 impl Default for VdCorput {
@@ -172,4 +254,39 @@ mod tests {
         assert_eq!(res[0], 512);  // 0.25 * 2048
         assert_eq!(res[1], 1458); // 2/3 * 2187
     }
+
+    #[test]
+    fn test_to_digits_roundtrips_with_from_digits() {
+        let digits = to_digits(13, 2);
+        assert_eq!(digits, vec![1, 0, 1, 1]);
+        assert_eq!(from_digits(&digits, 2), 13);
+    }
+
+    #[test]
+    fn test_to_digits_zero() {
+        assert_eq!(to_digits(0, 2), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_reflect_matches_vdcorput_pop() {
+        assert_eq!(reflect(1, 2, 10), 512); // 0.5 * 1024
+        assert_eq!(reflect(2, 2, 10), 256); // 0.25 * 1024
+    }
+
+    #[test]
+    fn test_vdcorput_getters_and_setters() {
+        let mut vdc = VdCorput::new(2, 10);
+        assert_eq!(vdc.base(), 2);
+        assert_eq!(vdc.scale(), 10);
+        assert_eq!(vdc.count(), 0);
+
+        vdc.set_count(5);
+        assert_eq!(vdc.count(), 5);
+        assert_eq!(vdc.pop(), 384); // matches reseed(5) behavior
+
+        vdc.set_base(3);
+        vdc.set_count(0);
+        assert_eq!(vdc.base(), 3);
+        assert_eq!(vdc.pop(), reflect(1, 3, 10)); // now reflects in base 3
+    }
 }
\ No newline at end of file
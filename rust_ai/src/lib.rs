@@ -0,0 +1,727 @@
+//! Low-Discrepancy Sequence (LDS) Generator
+//!
+//! This crate implements low-discrepancy sequence generators for floating-point
+//! output: the Van der Corput sequence, the Halton sequence (2D and N-dimensional),
+//! and several point-set generators built on top of them (unit circle, unit disk,
+//! unit sphere, and the 3-sphere via the Hopf fibration). These sequences are used
+//! to generate evenly distributed points in a space, which can be useful for
+//! sampling, optimization, or numerical integration.
+//!
+//! See the [`ilds`] module for the integer-valued counterparts.
+
+use std::f64::consts::PI;
+
+pub mod ilds;
+
+/// First few dozen primes, used as default bases for [`HaltonN`] and friends.
+pub const PRIME_TABLE: [u32; 50] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+    97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191,
+    193, 197, 199, 211, 223, 227, 229,
+];
+
+/// 64-bit bit-mixer (splitmix64) used to turn seed/position/history triples
+/// into well-distributed pseudo-random state for digit scrambling.
+fn mix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Derives an independent scramble seed for dimension `dim` from a single
+/// top-level seed, so that each dimension of a multi-dimensional generator
+/// gets its own scramble stream.
+fn derive_dimension_seed(seed: u64, dim: u32) -> u64 {
+    mix64(seed ^ mix64(dim as u64))
+}
+
+/// Applies Owen-style nested digit scrambling to `digit`, the `position`-th
+/// digit (1-indexed, least-significant-of-the-index first, matching the
+/// order digits are produced during radical-inversion) of a base-`base`
+/// expansion. `history` accumulates the already-scrambled digits so that the
+/// permutation used at each position depends on every digit scrambled so
+/// far, as in Owen scrambling. For `base == 2` this is equivalent to XOR-ing
+/// the digit with a pseudo-random bit derived from the same inputs.
+fn scramble_digit(base: u32, seed: u64, position: u32, history: u64, digit: u32) -> u32 {
+    let mut state = mix64(seed ^ (position as u64).wrapping_mul(0x9E37_79B1) ^ history);
+    let mut permutation: Vec<u32> = (0..base).collect();
+    for i in (1..base as usize).rev() {
+        state = mix64(state);
+        let j = (state % (i as u64 + 1)) as usize;
+        permutation.swap(i, j);
+    }
+    permutation[digit as usize]
+}
+
+/// Van der Corput sequence generator
+///
+/// Generates the radical-inverse of successive integers in the given base,
+/// producing a low-discrepancy sequence of values in `[0, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::VdCorput;
+/// let mut vdc = VdCorput::new(2);
+/// vdc.reseed(0);
+/// assert_eq!(vdc.pop(), 0.5);
+/// ```
+pub struct VdCorput {
+    base: u32,
+    count: u32,
+    scramble: Option<u64>,
+}
+
+impl VdCorput {
+    /// Creates a new Van der Corput sequence generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the number system
+    pub fn new(base: u32) -> Self {
+        Self {
+            base,
+            count: 0,
+            scramble: None,
+        }
+    }
+
+    /// Creates a new Van der Corput sequence generator with Owen-style nested
+    /// digit scrambling enabled, decorrelating it from unscrambled or
+    /// differently-seeded sequences while preserving low discrepancy.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the number system
+    /// * `seed` - Seed for the scrambling permutations; reusing the same seed
+    ///   reproduces the same scrambled sequence
+    pub fn with_scramble(base: u32, seed: u64) -> Self {
+        Self {
+            base,
+            count: 0,
+            scramble: Some(seed),
+        }
+    }
+
+    /// Generates the next value in the sequence
+    pub fn pop(&mut self) -> f64 {
+        self.count += 1;
+        let mut k = self.count;
+        let mut vdc = 0.0;
+        let mut denom = 1.0;
+        let base_f64 = self.base as f64;
+        let mut position: u32 = 0;
+        let mut history: u64 = 0;
+
+        while k != 0 {
+            denom *= base_f64;
+            let mut digit = k % self.base;
+            k /= self.base;
+
+            if let Some(seed) = self.scramble {
+                position += 1;
+                digit = scramble_digit(self.base, seed, position, history, digit);
+                history = mix64(history ^ (digit as u64).wrapping_add(position as u64));
+            }
+
+            vdc += digit as f64 / denom;
+        }
+        vdc
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed value that determines the starting point of the sequence generation
+    pub fn reseed(&mut self, seed: u32) {
+        self.count = seed;
+    }
+}
+
+impl Default for VdCorput {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+/// Halton sequence generator
+///
+/// Generates points in a 2-dimensional space using the Halton sequence, which
+/// pairs two Van der Corput sequences with coprime bases (typically 2 and 3).
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::Halton;
+/// let mut hgen = Halton::new([2, 3]);
+/// hgen.reseed(0);
+/// let point = hgen.pop();
+/// assert_eq!(point, [0.5, 1.0 / 3.0]);
+/// ```
+pub struct Halton {
+    vdc0: VdCorput,
+    vdc1: VdCorput,
+}
+
+impl Halton {
+    /// Creates a new Halton sequence generator with the given bases
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - An array of two coprime bases, one per dimension
+    pub fn new(base: [u32; 2]) -> Self {
+        Self {
+            vdc0: VdCorput::new(base[0]),
+            vdc1: VdCorput::new(base[1]),
+        }
+    }
+
+    /// Creates a new Halton sequence generator with Owen-style digit
+    /// scrambling enabled, using an independent scramble stream per
+    /// dimension derived from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - An array of two coprime bases, one per dimension
+    /// * `seed` - Top-level scramble seed; reusing the same seed reproduces
+    ///   the same scrambled sequence
+    pub fn with_scramble(base: [u32; 2], seed: u64) -> Self {
+        Self {
+            vdc0: VdCorput::with_scramble(base[0], derive_dimension_seed(seed, 0)),
+            vdc1: VdCorput::with_scramble(base[1], derive_dimension_seed(seed, 1)),
+        }
+    }
+
+    /// Generates the next point in the Halton sequence
+    pub fn pop(&mut self) -> [f64; 2] {
+        [self.vdc0.pop(), self.vdc1.pop()]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc0.reseed(seed);
+        self.vdc1.reseed(seed);
+    }
+}
+
+/// N-dimensional Halton sequence generator
+///
+/// Generalizes [`Halton`] to an arbitrary number of dimensions by pairing one
+/// Van der Corput sequence per base.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::HaltonN;
+/// let mut hgen = HaltonN::new(&[2, 3, 5]);
+/// hgen.reseed(0);
+/// assert_eq!(hgen.pop().len(), 3);
+/// ```
+pub struct HaltonN {
+    vdcs: Vec<VdCorput>,
+}
+
+impl HaltonN {
+    /// Creates a new N-dimensional Halton sequence generator
+    ///
+    /// # Arguments
+    ///
+    /// * `bases` - One base per dimension; bases should be pairwise coprime
+    pub fn new(bases: &[u32]) -> Self {
+        Self {
+            vdcs: bases.iter().map(|&base| VdCorput::new(base)).collect(),
+        }
+    }
+
+    /// Creates a new N-dimensional Halton sequence generator with
+    /// Owen-style digit scrambling enabled, using an independent scramble
+    /// stream per dimension derived from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bases` - One base per dimension; bases should be pairwise coprime
+    /// * `seed` - Top-level scramble seed; reusing the same seed reproduces
+    ///   the same scrambled sequence
+    pub fn with_scramble(bases: &[u32], seed: u64) -> Self {
+        Self {
+            vdcs: bases
+                .iter()
+                .enumerate()
+                .map(|(dim, &base)| VdCorput::with_scramble(base, derive_dimension_seed(seed, dim as u32)))
+                .collect(),
+        }
+    }
+
+    /// Generates the next point in the sequence
+    pub fn pop(&mut self) -> Vec<f64> {
+        self.vdcs.iter_mut().map(|vdc| vdc.pop()).collect()
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        for vdc in &mut self.vdcs {
+            vdc.reseed(seed);
+        }
+    }
+}
+
+/// Unit circle point generator
+///
+/// Maps a single Van der Corput sequence onto the unit circle.
+pub struct Circle {
+    vdc: VdCorput,
+}
+
+impl Circle {
+    /// Creates a new circle point generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the underlying Van der Corput sequence
+    pub fn new(base: u32) -> Self {
+        Self {
+            vdc: VdCorput::new(base),
+        }
+    }
+
+    /// Generates the next point on the unit circle
+    pub fn pop(&mut self) -> [f64; 2] {
+        let theta = self.vdc.pop() * 2.0 * PI;
+        [theta.cos(), theta.sin()]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc.reseed(seed);
+    }
+}
+
+/// Unit disk point generator
+///
+/// Generates points uniformly distributed over the unit disk by combining a
+/// radius sampled via `sqrt` (to preserve uniform area density) with an angle
+/// from a [`Circle`] generator.
+pub struct Disk {
+    vdc: VdCorput,
+    circle: Circle,
+}
+
+impl Disk {
+    /// Creates a new disk point generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - `[radius_base, angle_base]`, typically `[2, 3]`
+    pub fn new(base: [u32; 2]) -> Self {
+        Self {
+            vdc: VdCorput::new(base[0]),
+            circle: Circle::new(base[1]),
+        }
+    }
+
+    /// Generates the next point in the unit disk
+    pub fn pop(&mut self) -> [f64; 2] {
+        let radius = self.vdc.pop().sqrt();
+        let [cos_theta, sin_theta] = self.circle.pop();
+        [radius * cos_theta, radius * sin_theta]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc.reseed(seed);
+        self.circle.reseed(seed);
+    }
+}
+
+/// Unit sphere point generator
+///
+/// Generates points uniformly distributed over the surface of the unit sphere
+/// using Archimedes' cylindrical projection: `z` is sampled uniformly in
+/// `[-1, 1]` and the remaining latitude circle is sampled via [`Circle`].
+pub struct Sphere {
+    vdc: VdCorput,
+    circle: Circle,
+}
+
+impl Sphere {
+    /// Creates a new sphere point generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - `[z_base, angle_base]`, typically `[2, 3]`
+    pub fn new(base: [u32; 2]) -> Self {
+        Self {
+            vdc: VdCorput::new(base[0]),
+            circle: Circle::new(base[1]),
+        }
+    }
+
+    /// Generates the next point on the unit sphere
+    pub fn pop(&mut self) -> [f64; 3] {
+        let cos_phi = 2.0 * self.vdc.pop() - 1.0;
+        let sin_phi = (1.0 - cos_phi * cos_phi).sqrt();
+        let [cos_theta, sin_theta] = self.circle.pop();
+        [sin_phi * cos_theta, sin_phi * sin_theta, cos_phi]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc.reseed(seed);
+        self.circle.reseed(seed);
+    }
+}
+
+/// 3-sphere (S^3) point generator via the Hopf fibration
+///
+/// Generates points uniformly distributed over the surface of the unit
+/// 3-sphere in `R^4`, suitable for sampling uniform random rotations /
+/// quaternions.
+pub struct Sphere3Hopf {
+    vdc: VdCorput,
+    halton: Halton,
+}
+
+impl Sphere3Hopf {
+    /// Creates a new 3-sphere point generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - `[u1_base, theta1_base, theta2_base]`, typically `[2, 3, 5]`
+    pub fn new(base: [u32; 3]) -> Self {
+        Self {
+            vdc: VdCorput::new(base[0]),
+            halton: Halton::new([base[1], base[2]]),
+        }
+    }
+
+    /// Generates the next point on the 3-sphere
+    pub fn pop(&mut self) -> [f64; 4] {
+        let u1 = self.vdc.pop();
+        let [u2, u3] = self.halton.pop();
+        let s1 = (1.0 - u1).sqrt();
+        let s2 = u1.sqrt();
+        let theta1 = 2.0 * PI * u2;
+        let theta2 = 2.0 * PI * u3;
+        [
+            s1 * theta1.sin(),
+            s1 * theta1.cos(),
+            s2 * theta2.sin(),
+            s2 * theta2.cos(),
+        ]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc.reseed(seed);
+        self.halton.reseed(seed);
+    }
+}
+
+/// Reverses the 32 bits of `n`, used for the base-2 radical inverse.
+fn reverse_bits32(mut n: u32) -> u32 {
+    n = n.rotate_left(16);
+    n = ((n & 0xFF00_FF00) >> 8) | ((n & 0x00FF_00FF) << 8);
+    n = ((n & 0xF0F0_F0F0) >> 4) | ((n & 0x0F0F_0F0F) << 4);
+    n = ((n & 0xCCCC_CCCC) >> 2) | ((n & 0x3333_3333) << 2);
+    n = ((n & 0xAAAA_AAAA) >> 1) | ((n & 0x5555_5555) << 1);
+    n
+}
+
+/// Computes the second dimension of a Sobol (0,2)-sequence for index `n`
+/// using the classic Gray-code direction-number recurrence.
+fn sobol_dim1(n: u32) -> u32 {
+    let mut i: u32 = 1 << 31;
+    let mut acc: u32 = 0;
+    for bit in 0..32 {
+        if (n >> bit) & 1 != 0 {
+            acc ^= i;
+        }
+        i ^= i >> 1;
+    }
+    acc
+}
+
+/// Sobol (0,2)-sequence generator
+///
+/// Produces 2D samples that are genuinely (0,2)-stratified, a guarantee the
+/// radical-inverse [`Halton`] sequence does not provide. Dimension 0 is the
+/// base-2 radical inverse of the index; dimension 1 uses the Sobol Gray-code
+/// recurrence. Both dimensions accept an optional XOR scramble mask that
+/// randomizes the point set while preserving stratification.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::Sobol;
+/// let mut sgen = Sobol::new();
+/// sgen.reseed(0);
+/// let point = sgen.pop();
+/// assert!(point[0] >= 0.0 && point[0] < 1.0);
+/// assert!(point[1] >= 0.0 && point[1] < 1.0);
+/// ```
+pub struct Sobol {
+    count: u32,
+    scramble: [u32; 2],
+}
+
+impl Sobol {
+    /// Creates a new Sobol (0,2)-sequence generator
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            scramble: [0, 0],
+        }
+    }
+
+    /// Creates a new Sobol (0,2)-sequence generator with an XOR scramble
+    /// mask applied to each dimension's accumulator before scaling.
+    ///
+    /// # Arguments
+    ///
+    /// * `scramble` - `[dim0_mask, dim1_mask]`
+    pub fn with_scramble(scramble: [u32; 2]) -> Self {
+        Self { count: 0, scramble }
+    }
+
+    /// Generates the next point in the sequence
+    pub fn pop(&mut self) -> [f64; 2] {
+        self.count += 1;
+        let n = self.count;
+        let dim0 = reverse_bits32(n) ^ self.scramble[0];
+        let dim1 = sobol_dim1(n) ^ self.scramble[1];
+        let scale = 2f64.powi(-32);
+        [dim0 as f64 * scale, dim1 as f64 * scale]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.count = seed;
+    }
+}
+
+impl Default for Sobol {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Unit simplex point generator
+///
+/// Generates quasi-uniform points `x = [x_0, ..., x_{n-1}]` on the standard
+/// `(n-1)`-simplex, i.e. `x_i >= 0` and `sum(x_i) == 1`, by taking an
+/// `(n-1)`-dimensional Halton point in `(0, 1)`, appending `0` and `1`,
+/// sorting, and returning the `n` consecutive gaps. Useful for generating
+/// low-discrepancy weight vectors / barycentric coordinates, e.g. for
+/// Bayesian-bootstrap-style resampling.
+///
+/// # Examples
+///
+/// ```
+/// use lds_gen::Simplex;
+/// let mut sgen = Simplex::new(&[2, 3]);
+/// sgen.reseed(0);
+/// let point = sgen.pop();
+/// assert_eq!(point.len(), 3);
+/// assert!((point.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+pub struct Simplex {
+    halton_n: HaltonN,
+}
+
+impl Simplex {
+    /// Creates a new simplex point generator
+    ///
+    /// # Arguments
+    ///
+    /// * `bases` - `n - 1` bases for the underlying [`HaltonN`] generator,
+    ///   where `n` is the dimension of the simplex
+    pub fn new(bases: &[u32]) -> Self {
+        Self {
+            halton_n: HaltonN::new(bases),
+        }
+    }
+
+    /// Generates the next point on the simplex
+    pub fn pop(&mut self) -> Vec<f64> {
+        let mut values = self.halton_n.pop();
+        for &value in &values {
+            debug_assert!((0.0..=1.0).contains(&value));
+        }
+        values.push(0.0);
+        values.push(1.0);
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u32) {
+        self.halton_n.reseed(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vdcorput_pop() {
+        let mut vdc = VdCorput::new(2);
+        vdc.reseed(0);
+        assert_eq!(vdc.pop(), 0.5);
+        assert_eq!(vdc.pop(), 0.25);
+        assert_eq!(vdc.pop(), 0.75);
+        assert_eq!(vdc.pop(), 0.125);
+    }
+
+    #[test]
+    fn test_vdcorput_reseed() {
+        let mut vdc = VdCorput::new(2);
+        vdc.reseed(5);
+        assert_eq!(vdc.pop(), 0.375);
+        vdc.reseed(0);
+        assert_eq!(vdc.pop(), 0.5);
+    }
+
+    #[test]
+    fn test_halton_pop() {
+        let mut hgen = Halton::new([2, 3]);
+        hgen.reseed(0);
+        let point = hgen.pop();
+        assert_eq!(point, [0.5, 1.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_halton_n_matches_halton() {
+        let mut hgen = Halton::new([2, 3]);
+        let mut hgen_n = HaltonN::new(&[2, 3]);
+        hgen.reseed(7);
+        hgen_n.reseed(7);
+        assert_eq!(hgen.pop().to_vec(), hgen_n.pop());
+    }
+
+    #[test]
+    fn test_circle_pop_on_unit_circle() {
+        let mut cgen = Circle::new(2);
+        cgen.reseed(0);
+        let [x, y] = cgen.pop();
+        assert!(((x * x + y * y) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_disk_pop_within_unit_disk() {
+        let mut dgen = Disk::new([2, 3]);
+        for _ in 0..20 {
+            let [x, y] = dgen.pop();
+            assert!(x * x + y * y <= 1.0 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sphere_pop_on_unit_sphere() {
+        let mut sgen = Sphere::new([2, 3]);
+        for _ in 0..20 {
+            let [x, y, z] = sgen.pop();
+            assert!(((x * x + y * y + z * z) - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sphere3hopf_pop_on_unit_3sphere() {
+        let mut sgen = Sphere3Hopf::new([2, 3, 5]);
+        for _ in 0..20 {
+            let point = sgen.pop();
+            let norm_sq: f64 = point.iter().map(|v| v * v).sum();
+            assert!((norm_sq - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sobol_pop_within_unit_square() {
+        let mut sgen = Sobol::new();
+        for _ in 0..50 {
+            let [x, y] = sgen.pop();
+            assert!((0.0..1.0).contains(&x));
+            assert!((0.0..1.0).contains(&y));
+        }
+    }
+
+    #[test]
+    fn test_sobol_reseed_reproducible() {
+        let mut sgen = Sobol::new();
+        sgen.reseed(10);
+        let first = sgen.pop();
+        sgen.reseed(10);
+        let again = sgen.pop();
+        assert_eq!(first, again);
+    }
+
+    #[test]
+    fn test_sobol_scramble_changes_output() {
+        let mut plain = Sobol::new();
+        let mut scrambled = Sobol::with_scramble([0xDEAD_BEEF, 0xCAFE_F00D]);
+        assert_ne!(plain.pop(), scrambled.pop());
+    }
+
+    #[test]
+    fn test_simplex_pop_sums_to_one() {
+        let mut sgen = Simplex::new(&[2, 3]);
+        sgen.reseed(0);
+        for _ in 0..20 {
+            let point = sgen.pop();
+            assert_eq!(point.len(), 3);
+            assert!(point.iter().all(|&x| x >= 0.0));
+            assert!((point.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_differs_from_unscrambled() {
+        let mut vdc = VdCorput::new(2);
+        let mut scrambled = VdCorput::with_scramble(2, 42);
+        let plain: Vec<f64> = (0..10).map(|_| vdc.pop()).collect();
+        let mixed: Vec<f64> = (0..10).map(|_| scrambled.pop()).collect();
+        assert_ne!(plain, mixed);
+        assert!(mixed.iter().all(|&x| (0.0..1.0).contains(&x)));
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_reproducible_with_same_seed() {
+        let mut a = VdCorput::with_scramble(2, 7);
+        let mut b = VdCorput::with_scramble(2, 7);
+        for _ in 0..10 {
+            assert_eq!(a.pop(), b.pop());
+        }
+    }
+
+    #[test]
+    fn test_scrambled_halton_uses_independent_streams_per_dimension() {
+        let mut hgen = Halton::with_scramble([2, 3], 11);
+        let [x0, y0] = hgen.pop();
+        let mut vdc0 = VdCorput::with_scramble(2, derive_dimension_seed(11, 0));
+        let mut vdc1 = VdCorput::with_scramble(3, derive_dimension_seed(11, 1));
+        assert_eq!(x0, vdc0.pop());
+        assert_eq!(y0, vdc1.pop());
+    }
+
+    #[test]
+    fn test_scrambled_halton_n_reproducible() {
+        let mut a = HaltonN::with_scramble(&[2, 3, 5], 99);
+        let mut b = HaltonN::with_scramble(&[2, 3, 5], 99);
+        a.reseed(4);
+        b.reseed(4);
+        assert_eq!(a.pop(), b.pop());
+    }
+
+    #[test]
+    fn test_simplex_reseed() {
+        let mut sgen = Simplex::new(&[2, 3, 5]);
+        sgen.reseed(3);
+        let first = sgen.pop();
+        sgen.reseed(3);
+        let again = sgen.pop();
+        assert_eq!(first, again);
+    }
+}
@@ -2,12 +2,37 @@
 //!
 //! This binary provides a simple CLI to generate low-discrepancy sequences.
 
-use clap::{Parser, Subcommand};
-use lds_gen::{VdCorput, Halton, Circle, Disk, Sphere, Sphere3Hopf, HaltonN, PRIME_TABLE};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use lds_gen::{Circle, Disk, Halton, HaltonN, Sphere, Sphere3Hopf, VdCorput, PRIME_TABLE};
+
+/// Output format for generated points
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human-readable, one point per line (default)
+    Text,
+    /// One row per point, one column per coordinate
+    Csv,
+    /// A single JSON array of arrays
+    Json,
+    /// One JSON array per line (newline-delimited JSON)
+    Ndjson,
+}
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    /// Output format for generated points
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    format: Format,
+
+    /// Write generated points to a file instead of stdout (`primes` always prints to stdout)
+    #[arg(long, global = true)]
+    output: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -48,6 +73,21 @@ enum Commands {
         seed: u32,
     },
 
+    /// Generate N-dimensional Halton sequence
+    HaltonN {
+        /// Comma-separated bases, one per dimension (default: 2,3,5)
+        #[arg(long, value_delimiter = ',', default_value = "2,3,5")]
+        bases: Vec<u32>,
+
+        /// Number of points to generate (default: 10)
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+
+        /// Starting seed (default: 0)
+        #[arg(short, long, default_value_t = 0)]
+        seed: u32,
+    },
+
     /// Generate points on unit circle
     Circle {
         /// Base of the sequence (default: 2)
@@ -63,6 +103,67 @@ enum Commands {
         seed: u32,
     },
 
+    /// Generate points in unit disk
+    Disk {
+        /// Radius base (default: 2)
+        #[arg(long, default_value_t = 2)]
+        base1: u32,
+
+        /// Angle base (default: 3)
+        #[arg(long, default_value_t = 3)]
+        base2: u32,
+
+        /// Number of points to generate (default: 10)
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+
+        /// Starting seed (default: 0)
+        #[arg(short, long, default_value_t = 0)]
+        seed: u32,
+    },
+
+    /// Generate points on unit sphere
+    Sphere {
+        /// Z base (default: 2)
+        #[arg(long, default_value_t = 2)]
+        base1: u32,
+
+        /// Angle base (default: 3)
+        #[arg(long, default_value_t = 3)]
+        base2: u32,
+
+        /// Number of points to generate (default: 10)
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+
+        /// Starting seed (default: 0)
+        #[arg(short, long, default_value_t = 0)]
+        seed: u32,
+    },
+
+    /// Generate points on the 3-sphere via the Hopf fibration
+    Sphere3Hopf {
+        /// First base (default: 2)
+        #[arg(long, default_value_t = 2)]
+        base1: u32,
+
+        /// Second base (default: 3)
+        #[arg(long, default_value_t = 3)]
+        base2: u32,
+
+        /// Third base (default: 5)
+        #[arg(long, default_value_t = 5)]
+        base3: u32,
+
+        /// Number of points to generate (default: 10)
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+
+        /// Starting seed (default: 0)
+        #[arg(short, long, default_value_t = 0)]
+        seed: u32,
+    },
+
     /// List first N primes from prime table
     Primes {
         /// Number of primes to list (default: 20)
@@ -71,44 +172,191 @@ enum Commands {
     },
 }
 
+/// Streams generated points to stdout or a file in the requested format,
+/// without buffering the full point set in memory.
+struct PointWriter {
+    format: Format,
+    out: Box<dyn Write>,
+    count: usize,
+}
+
+impl PointWriter {
+    fn new(format: Format, output: &Option<PathBuf>, label: &str, columns: &[&str]) -> io::Result<Self> {
+        let out: Box<dyn Write> = match output {
+            Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+            None => Box::new(BufWriter::new(io::stdout())),
+        };
+        let mut writer = Self {
+            format,
+            out,
+            count: 0,
+        };
+        match writer.format {
+            Format::Text => writeln!(writer.out, "{}", label)?,
+            Format::Csv => writeln!(writer.out, "{}", columns.join(","))?,
+            Format::Json => write!(writer.out, "[")?,
+            Format::Ndjson => {}
+        }
+        Ok(writer)
+    }
+
+    fn write_point(&mut self, values: &[f64]) -> io::Result<()> {
+        self.count += 1;
+        match self.format {
+            Format::Text => {
+                let formatted: Vec<String> = values.iter().map(|v| format!("{:.6}", v)).collect();
+                if formatted.len() == 1 {
+                    writeln!(self.out, "  {}: {}", self.count, formatted[0])?;
+                } else {
+                    writeln!(self.out, "  {}: [{}]", self.count, formatted.join(", "))?;
+                }
+            }
+            Format::Csv => {
+                let formatted: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                writeln!(self.out, "{}", formatted.join(","))?;
+            }
+            Format::Json => {
+                if self.count > 1 {
+                    write!(self.out, ",")?;
+                }
+                let formatted: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                write!(self.out, "[{}]", formatted.join(","))?;
+            }
+            Format::Ndjson => {
+                let formatted: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                writeln!(self.out, "[{}]", formatted.join(","))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        if matches!(self.format, Format::Json) {
+            writeln!(self.out, "]")?;
+        }
+        self.out.flush()
+    }
+}
+
 fn main() {
+    if let Err(err) = run() {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> io::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
         Commands::Vdc { base, count, seed } => {
-            println!("Van der Corput sequence (base: {}, seed: {}):", base, seed);
             let mut vgen = VdCorput::new(base);
             vgen.reseed(seed);
-            for i in 0..count {
-                println!("  {}: {}", i + 1, vgen.pop());
+            let label = format!("Van der Corput sequence (base: {}, seed: {}):", base, seed);
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x"])?;
+            for _ in 0..count {
+                writer.write_point(&[vgen.pop()])?;
             }
+            writer.finish()
         }
 
-        Commands::Halton { base1, base2, count, seed } => {
-            println!("Halton sequence (bases: [{}, {}], seed: {}):", base1, base2, seed);
+        Commands::Halton {
+            base1,
+            base2,
+            count,
+            seed,
+        } => {
             let mut hgen = Halton::new([base1, base2]);
             hgen.reseed(seed);
-            for i in 0..count {
-                let point = hgen.pop();
-                println!("  {}: [{:.6}, {:.6}]", i + 1, point[0], point[1]);
+            let label = format!("Halton sequence (bases: [{}, {}], seed: {}):", base1, base2, seed);
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x0", "x1"])?;
+            for _ in 0..count {
+                writer.write_point(&hgen.pop())?;
+            }
+            writer.finish()
+        }
+
+        Commands::HaltonN { bases, count, seed } => {
+            let mut hgen = HaltonN::new(&bases);
+            hgen.reseed(seed);
+            let label = format!("Halton-N sequence (bases: {:?}, seed: {}):", bases, seed);
+            let columns: Vec<String> = (0..bases.len()).map(|i| format!("x{}", i)).collect();
+            let column_refs: Vec<&str> = columns.iter().map(String::as_str).collect();
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &column_refs)?;
+            for _ in 0..count {
+                writer.write_point(&hgen.pop())?;
             }
+            writer.finish()
         }
 
         Commands::Circle { base, count, seed } => {
-            println!("Circle points (base: {}, seed: {}):", base, seed);
             let mut cgen = Circle::new(base);
             cgen.reseed(seed);
-            for i in 0..count {
-                let point = cgen.pop();
-                println!("  {}: [{:.6}, {:.6}]", i + 1, point[0], point[1]);
+            let label = format!("Circle points (base: {}, seed: {}):", base, seed);
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x", "y"])?;
+            for _ in 0..count {
+                writer.write_point(&cgen.pop())?;
+            }
+            writer.finish()
+        }
+
+        Commands::Disk {
+            base1,
+            base2,
+            count,
+            seed,
+        } => {
+            let mut dgen = Disk::new([base1, base2]);
+            dgen.reseed(seed);
+            let label = format!("Disk points (bases: [{}, {}], seed: {}):", base1, base2, seed);
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x", "y"])?;
+            for _ in 0..count {
+                writer.write_point(&dgen.pop())?;
+            }
+            writer.finish()
+        }
+
+        Commands::Sphere {
+            base1,
+            base2,
+            count,
+            seed,
+        } => {
+            let mut sgen = Sphere::new([base1, base2]);
+            sgen.reseed(seed);
+            let label = format!("Sphere points (bases: [{}, {}], seed: {}):", base1, base2, seed);
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x", "y", "z"])?;
+            for _ in 0..count {
+                writer.write_point(&sgen.pop())?;
+            }
+            writer.finish()
+        }
+
+        Commands::Sphere3Hopf {
+            base1,
+            base2,
+            base3,
+            count,
+            seed,
+        } => {
+            let mut sgen = Sphere3Hopf::new([base1, base2, base3]);
+            sgen.reseed(seed);
+            let label = format!(
+                "Sphere3Hopf points (bases: [{}, {}, {}], seed: {}):",
+                base1, base2, base3, seed
+            );
+            let mut writer = PointWriter::new(cli.format, &cli.output, &label, &["x0", "x1", "x2", "x3"])?;
+            for _ in 0..count {
+                writer.write_point(&sgen.pop())?;
             }
+            writer.finish()
         }
 
         Commands::Primes { count } => {
             let n = count.min(PRIME_TABLE.len());
             println!("First {} primes:", n);
-            for i in 0..n {
-                print!("{} ", PRIME_TABLE[i]);
+            for (i, prime) in PRIME_TABLE.iter().enumerate().take(n) {
+                print!("{} ", prime);
                 if (i + 1) % 10 == 0 {
                     println!();
                 }
@@ -116,6 +364,7 @@ fn main() {
             if n % 10 != 0 {
                 println!();
             }
+            Ok(())
         }
     }
-}
\ No newline at end of file
+}